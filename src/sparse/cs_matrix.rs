@@ -36,10 +36,10 @@ pub struct CsVecStorage<N: Scalar, R: Dim, C: Dim>
 where
     DefaultAllocator: Allocator<usize, C>,
 {
-    shape: (R, C),
-    p: VectorN<usize, C>,
-    i: Vec<usize>,
-    vals: Vec<N>,
+    pub(crate) shape: (R, C),
+    pub(crate) p: VectorN<usize, C>,
+    pub(crate) i: Vec<usize>,
+    pub(crate) vals: Vec<N>,
 }
 
 impl<N: Scalar, R: Dim, C: Dim> CsStorage<N, R, C> for CsVecStorage<N, R, C>
@@ -134,7 +134,102 @@ where
     }
 }
 
-fn cumsum<D: Dim>(a: &mut VectorN<usize, D>, b: &mut VectorN<usize, D>) -> usize
+/// An error describing why a `CsMatrix`'s internal structure is invalid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CsStructureError {
+    /// The column pointers `p` are not monotonically increasing.
+    NonMonotonicColumnPointers,
+    /// A row index is out of bounds for this matrix's number of rows.
+    RowIndexOutOfBounds {
+        /// The out-of-bounds row index.
+        row: usize,
+        /// This matrix's number of rows.
+        nrows: usize,
+    },
+}
+
+impl<N: Scalar + Zero + ClosedAdd, R: Dim, C: Dim> CsMatrix<N, R, C>
+where
+    DefaultAllocator: Allocator<usize, C>,
+{
+    /// Sorts the row indices of each column in increasing order, summing the values of any
+    /// duplicate row indices together.
+    ///
+    /// Several operations on this type (the triangular solves, and efficient column merges)
+    /// assume each column's row indices are sorted and unique; the result of arithmetic
+    /// operations such as `Mul` and `Add` may not satisfy this and can be normalized with this
+    /// method.
+    pub fn sort_and_dedup(&mut self) {
+        let ncols = self.data.shape().1;
+        let mut permutation = Vec::new();
+        let mut new_i = Vec::with_capacity(self.data.i.len());
+        let mut new_vals = Vec::with_capacity(self.data.vals.len());
+        let mut new_p = VectorN::zeros_generic(ncols, U1);
+
+        for j in 0..ncols.value() {
+            new_p[j] = new_i.len();
+            let range = self.data.column_range(j);
+
+            permutation.clear();
+            permutation.extend(0..range.end - range.start);
+            permutation.sort_by_key(|&k| self.data.i[range.start + k]);
+
+            for k in permutation.drain(..) {
+                let i = self.data.i[range.start + k];
+                let v = self.data.vals[range.start + k];
+
+                if new_i.len() > new_p[j] && *new_i.last().unwrap() == i {
+                    let last = new_vals.len() - 1;
+                    new_vals[last] += v;
+                } else {
+                    new_i.push(i);
+                    new_vals.push(v);
+                }
+            }
+        }
+
+        self.data.p = new_p;
+        self.data.i = new_i;
+        self.data.vals = new_vals;
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim> CsMatrix<N, R, C>
+where
+    DefaultAllocator: Allocator<usize, C>,
+{
+    /// Checks that this matrix's internal structure is valid: the column pointers `p` are
+    /// monotonically increasing, and every stored row index is within bounds.
+    pub fn check_structure(&self) -> Result<(), CsStructureError> {
+        let (nrows, ncols) = self.data.shape();
+        let nvalues = self.data.vals.len();
+
+        for j in 0..ncols.value() {
+            let next = if j + 1 == ncols.value() {
+                nvalues
+            } else {
+                self.data.p[j + 1]
+            };
+
+            if self.data.p[j] > next {
+                return Err(CsStructureError::NonMonotonicColumnPointers);
+            }
+        }
+
+        for &row in &self.data.i {
+            if row >= nrows.value() {
+                return Err(CsStructureError::RowIndexOutOfBounds {
+                    row,
+                    nrows: nrows.value(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn cumsum<D: Dim>(a: &mut VectorN<usize, D>, b: &mut VectorN<usize, D>) -> usize
 where
     DefaultAllocator: Allocator<usize, D>,
 {
@@ -155,6 +250,28 @@ impl<N: Scalar, R: Dim, C: Dim, S: CsStorage<N, R, C>> CsMatrix<N, R, C, S> {
         self.data.nvalues()
     }
 
+    /// Returns `true` if the row indices of every column are sorted in strictly increasing
+    /// order (i.e. without duplicates).
+    pub fn is_sorted(&self) -> bool {
+        let ncols = self.data.shape().1;
+
+        for j in 0..ncols.value() {
+            let mut prev = None;
+
+            for vi in self.data.column_range(j) {
+                let i = self.data.row_index(vi);
+
+                if prev.map_or(false, |p| i <= p) {
+                    return false;
+                }
+
+                prev = Some(i);
+            }
+        }
+
+        true
+    }
+
     pub fn transpose(&self) -> CsMatrix<N, C, R>
     where
         DefaultAllocator: Allocator<usize, R>,
@@ -290,22 +407,64 @@ impl<N: Scalar + Zero + ClosedAdd + ClosedMul, D: Dim, S: StorageMut<N, D>> Vect
         }
     }
 
-    /*
-    pub fn gemv_sparse<R2: Dim, C2: Dim, S2>(&mut self, alpha: N, a: &CsMatrix<N, R2, C2, S2>, x: &DVector<N>, beta: N)
-        where
-            S2: CsStorage<N, R2, C2> {
-        let col2 = a.column(0);
-        let val = unsafe { *x.vget_unchecked(0) };
-        self.axpy_sparse(alpha * val, &col2, beta);
-    
-        for j in 1..ncols2 {
-            let col2 = a.column(j);
-            let val = unsafe { *x.vget_unchecked(j) };
-    
-            self.axpy_sparse(alpha * val, &col2, N::one());
+    /// Computes `self = alpha * a * x + beta * self`, where `a` is a sparse matrix and `x` a
+    /// dense vector.
+    pub fn gemv_cs<R2: Dim, C2: Dim, S2, D3, S3>(
+        &mut self,
+        alpha: N,
+        a: &CsMatrix<N, R2, C2, S2>,
+        x: &Vector<N, D3, S3>,
+        beta: N,
+    ) where
+        S2: CsStorage<N, R2, C2>,
+        S3: Storage<N, D3>,
+        ShapeConstraint: DimEq<D, R2> + DimEq<C2, D3>,
+    {
+        if beta.is_zero() {
+            self.fill(N::zero());
+        } else {
+            *self *= beta;
+        }
+
+        let ncols = a.data.shape().1;
+
+        for j in 0..ncols.value() {
+            let alpha_xj = alpha * x[j];
+
+            for vi in a.data.column_range(j) {
+                let i = a.data.row_index(vi);
+                self[i] += *a.data.get_value(vi) * alpha_xj;
+            }
         }
     }
-    */
+}
+
+impl<'a, 'b, N, R1, C1, S1, R2, C2, S2> Mul<&'b Matrix<N, R2, C2, S2>> for &'a CsMatrix<N, R1, C1, S1>
+where
+    N: Scalar + Zero + ClosedAdd + ClosedMul,
+    R1: Dim,
+    C1: Dim,
+    R2: Dim,
+    C2: Dim,
+    S1: CsStorage<N, R1, C1>,
+    S2: Storage<N, R2, C2>,
+    ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    DefaultAllocator: Allocator<N, R1, C2>,
+{
+    type Output = MatrixMN<N, R1, C2>;
+
+    fn mul(self, rhs: &'b Matrix<N, R2, C2, S2>) -> Self::Output {
+        let nrows1 = self.data.shape().0;
+        let ncols2 = rhs.data.shape().1;
+        let mut res = MatrixMN::zeros_generic(nrows1, ncols2);
+
+        for j in 0..ncols2.value() {
+            res.column_mut(j)
+                .gemv_cs(N::one(), self, &rhs.column(j), N::zero());
+        }
+
+        res
+    }
 }
 
 impl<'a, 'b, N, R1, R2, C1, C2, S1, S2> Mul<&'b CsMatrix<N, R2, C2, S2>>
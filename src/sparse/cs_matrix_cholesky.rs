@@ -0,0 +1,366 @@
+use alga::general::Real;
+
+use allocator::Allocator;
+use constraint::{DimEq, ShapeConstraint};
+use sparse::cs_matrix::{CsMatrix, CsStorage};
+use storage::{Storage, StorageMut};
+use {DefaultAllocator, Dim, Matrix, MatrixMN, VectorN, U1};
+
+/// The index used to mark a column of the elimination tree as having no parent (i.e. a root).
+const NO_PARENT: usize = usize::max_value();
+
+/// A Cholesky factorization `L * Lᵀ` of a sparse symmetric-positive-definite matrix, with `L`
+/// stored as a lower-triangular `CsMatrix`.
+pub struct CsCholesky<N: Real, D: Dim>
+where
+    DefaultAllocator: Allocator<usize, D>,
+{
+    l: CsMatrix<N, D, D>,
+}
+
+impl<N: Real, D: Dim> CsCholesky<N, D>
+where
+    DefaultAllocator: Allocator<usize, D>,
+{
+    /// Computes the Cholesky factorization `m = L * Lᵀ` of the symmetric-positive-definite
+    /// matrix `m`.
+    ///
+    /// Only the entries of `m` on or below the diagonal are read to determine the values of
+    /// `L`; the elimination tree itself is built from the strictly upper-triangular part of
+    /// `m` (which, as `m` is symmetric, mirrors the strictly lower-triangular part). Returns
+    /// `None` if `m` is not symmetric, or if a nonpositive pivot is encountered, meaning `m` is
+    /// not positive-definite.
+    pub fn new<S: CsStorage<N, D, D>>(m: &CsMatrix<N, D, D, S>) -> Option<Self> {
+        let (nrows, ncols) = m.data.shape();
+        assert_eq!(
+            nrows.value(),
+            ncols.value(),
+            "The matrix `m` must be square to compute its Cholesky factorization."
+        );
+
+        let n = nrows.value();
+
+        // Reject asymmetric input: every stored `(i, j)` needs a `(j, i)` with the same value.
+        for j in 0..n {
+            for vi in m.data.column_range(j) {
+                let i = m.data.row_index(vi);
+                let mirrored = m
+                    .data
+                    .column_range(i)
+                    .find(|vk| m.data.row_index(*vk) == j)?;
+
+                if *m.data.get_value(mirrored) != *m.data.get_value(vi) {
+                    return None;
+                }
+            }
+        }
+
+        let parent = Self::elimination_tree(m);
+        let (mut l, pattern) = Self::symbolic_phase(m, &parent);
+
+        // Up-looking numeric Cholesky factorization.
+        let mut work = VectorN::zeros_generic(nrows, U1);
+        let mut timestamps = vec![0; n];
+        let mut reach = Vec::new();
+
+        for j in 0..n {
+            for vi in m.data.column_range(j) {
+                let i = m.data.row_index(vi);
+
+                if i >= j {
+                    work[i] = *m.data.get_value(vi);
+                }
+            }
+
+            Self::ereach(m, j, &parent, &mut timestamps, j + 1, &mut reach);
+
+            for &k in &reach {
+                let entry = Self::find_entry(&l, j, k)?;
+                let ljk = l.data.get_value(entry);
+
+                for vi in l.data.column_range(k) {
+                    let i = l.data.row_index(vi);
+
+                    if i >= j {
+                        work[i] -= *ljk * *l.data.get_value(vi);
+                    }
+                }
+            }
+
+            let diag = work[j];
+
+            if diag <= N::zero() {
+                return None;
+            }
+
+            let diag = diag.sqrt();
+            let start = l.data.p[j];
+            l.data.i[start] = j;
+            l.data.vals[start] = diag;
+            work[j] = N::zero();
+
+            for (k, &i) in pattern[j].iter().enumerate() {
+                l.data.i[start + k + 1] = i;
+                l.data.vals[start + k + 1] = work[i] / diag;
+                work[i] = N::zero();
+            }
+        }
+
+        Some(CsCholesky { l })
+    }
+
+    /// The lower-triangular factor `L` such that `L * Lᵀ` is the factorized matrix.
+    pub fn l(&self) -> &CsMatrix<N, D, D> {
+        &self.l
+    }
+
+    /// Solves the system `self * x = b` and overwrites `b` with the result `x`.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: DimEq<R2, D>,
+    {
+        let n = self.l.data.shape().0.value();
+
+        // Forward-substitution: solve `L * y = b`, reusing the generic triangular solve. `L`'s
+        // structure is our own and always genuinely lower-triangular, so this cannot fail.
+        let success = self.l.solve_lower_triangular_mut(b);
+        assert!(success, "CsCholesky: invalid elimination tree.");
+
+        // Back-substitution: solve `Lᵀ * x = y` directly against `L`'s own (lower-triangular)
+        // storage, since `L`'s transpose is never materialized.
+        for mut col in b.column_iter_mut() {
+            for j in (0..n).rev() {
+                let mut it = self.l.data.column_range(j);
+                let diag_i = it.next().unwrap();
+                let mut xj = col[j];
+
+                for vi in it {
+                    let i = self.l.data.row_index(vi);
+                    xj -= *self.l.data.get_value(vi) * col[i];
+                }
+
+                col[j] = xj / *self.l.data.get_value(diag_i);
+            }
+        }
+    }
+
+    /// Solves the system `self * x = b` and returns the result `x`.
+    pub fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<N, R2, C2, S2>) -> MatrixMN<N, R2, C2>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: DimEq<R2, D>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.solve_mut(&mut res);
+        res
+    }
+
+    // Finds the storage index of the entry `(row, col)` within `L`'s column `col`. Returns
+    // `None` if no such entry exists. The symbolic phase stores row `j` in column `k` for every
+    // `k` in the reach of `j`, so the lookups made by `new` always find their entry.
+    fn find_entry(l: &CsMatrix<N, D, D>, row: usize, col: usize) -> Option<usize> {
+        l.data
+            .column_range(col)
+            .find(|vi| l.data.row_index(*vi) == row)
+    }
+
+    // Computes the parent of each column in the elimination tree of `m`, using a pass over the
+    // strictly upper-triangular part of `m` with path compression.
+    fn elimination_tree<S: CsStorage<N, D, D>>(m: &CsMatrix<N, D, D, S>) -> VectorN<usize, D>
+    where
+        DefaultAllocator: Allocator<usize, D>,
+    {
+        let (nrows, ncols) = m.data.shape();
+        let mut parent = VectorN::zeros_generic(nrows, U1);
+        let mut ancestor = VectorN::zeros_generic(nrows, U1);
+
+        for i in 0..nrows.value() {
+            parent[i] = NO_PARENT;
+            ancestor[i] = NO_PARENT;
+        }
+
+        for k in 0..ncols.value() {
+            for vi in m.data.column_range(k) {
+                let mut i = m.data.row_index(vi);
+
+                while i < k {
+                    let i_next = ancestor[i];
+                    ancestor[i] = k;
+
+                    if i_next == NO_PARENT {
+                        parent[i] = k;
+                        break;
+                    }
+
+                    i = i_next;
+                }
+            }
+        }
+
+        parent
+    }
+
+    // Computes the reachable set, in the elimination tree, of the rows of column `j` of `m`
+    // that lie strictly above the diagonal. This is exactly the set of columns `k < j` such
+    // that `L[j, k] != 0`.
+    fn ereach<S: CsStorage<N, D, D>>(
+        m: &CsMatrix<N, D, D, S>,
+        j: usize,
+        parent: &VectorN<usize, D>,
+        timestamps: &mut [usize],
+        timestamp: usize,
+        reach: &mut Vec<usize>,
+    ) where
+        DefaultAllocator: Allocator<usize, D>,
+    {
+        reach.clear();
+
+        // Mark `j` itself as visited so that a walk up the elimination tree from some `i < j`
+        // stops upon reaching `j`, instead of continuing past it into columns not yet computed.
+        timestamps[j] = timestamp;
+
+        for vi in m.data.column_range(j) {
+            let mut i = m.data.row_index(vi);
+
+            if i >= j {
+                continue;
+            }
+
+            let start = reach.len();
+
+            while i != NO_PARENT && timestamps[i] != timestamp {
+                reach.push(i);
+                timestamps[i] = timestamp;
+                i = parent[i];
+            }
+
+            reach[start..].reverse();
+        }
+    }
+
+    // Symbolic phase: computes `L`'s sparsity pattern (and allocates its storage) from the
+    // elimination tree, along with each column's pattern of strictly-below-diagonal rows in
+    // increasing order (needed by the numeric phase to fill `L` in-place).
+    fn symbolic_phase<S: CsStorage<N, D, D>>(
+        m: &CsMatrix<N, D, D, S>,
+        parent: &VectorN<usize, D>,
+    ) -> (CsMatrix<N, D, D>, Vec<Vec<usize>>)
+    where
+        DefaultAllocator: Allocator<usize, D>,
+    {
+        let (nrows, ncols) = m.data.shape();
+        let n = ncols.value();
+        let mut pattern = vec![Vec::new(); n];
+        let mut timestamps = vec![0; n];
+        let mut reach = Vec::new();
+
+        for j in 0..n {
+            Self::ereach(m, j, parent, &mut timestamps, j + 1, &mut reach);
+
+            for &k in &reach {
+                pattern[k].push(j);
+            }
+        }
+
+        let nvals = (0..n).map(|j| pattern[j].len() + 1).sum();
+        let mut l = CsMatrix::new_uninitialized_generic(nrows, ncols, nvals);
+        let mut nz = 0;
+
+        for j in 0..n {
+            l.data.p[j] = nz;
+            nz += pattern[j].len() + 1;
+        }
+
+        (l, pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CsCholesky;
+    use sparse::CsMatrix;
+    use {DMatrix, DVector, Dynamic};
+
+    fn to_dense(m: &CsMatrix<f64, Dynamic, Dynamic>) -> DMatrix<f64> {
+        let (nrows, ncols) = m.data.shape();
+        let mut dense = DMatrix::zeros(nrows.value(), ncols.value());
+
+        for j in 0..ncols.value() {
+            for vi in m.data.column_range(j) {
+                let i = m.data.row_index(vi);
+                dense[(i, j)] = *m.data.get_value(vi);
+            }
+        }
+
+        dense
+    }
+
+    #[test]
+    fn factorizes_spd_matrix() {
+        let dense = DMatrix::from_row_slice(3, 3, &[4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0]);
+        let a = CsMatrix::from(dense.clone());
+        let chol = CsCholesky::new(&a).expect("matrix is SPD");
+        let l = to_dense(chol.l());
+        let reconstructed = &l * l.transpose();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((reconstructed[(i, j)] - dense[(i, j)]).abs() < 1.0e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn factorizes_star_pattern_matrix() {
+        // A 4x4 "arrow"/"star" matrix: column 3 has off-diagonal entries in rows 0, 1 and 2,
+        // giving the elimination tree a branch (0, 1 and 2 are all parented directly by 3)
+        // rather than the simple chain exercised by `factorizes_spd_matrix`.
+        #[rustfmt::skip]
+        let dense = DMatrix::from_row_slice(4, 4, &[
+            2.0, 0.0, 0.0, 1.0,
+            0.0, 2.0, 0.0, 1.0,
+            0.0, 0.0, 2.0, 1.0,
+            1.0, 1.0, 1.0, 4.0,
+        ]);
+        let a = CsMatrix::from(dense.clone());
+        let chol = CsCholesky::new(&a).expect("matrix is SPD");
+        let l = to_dense(chol.l());
+        let reconstructed = &l * l.transpose();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((reconstructed[(i, j)] - dense[(i, j)]).abs() < 1.0e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn solves_linear_system() {
+        let dense = DMatrix::from_row_slice(3, 3, &[4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0]);
+        let a = CsMatrix::from(dense.clone());
+        let chol = CsCholesky::new(&a).expect("matrix is SPD");
+        let b = DVector::from_vec(vec![1.0, 2.0, 3.0]);
+        let x = chol.solve(&b);
+        let residual = dense * &x - &b;
+
+        for i in 0..3 {
+            assert!(residual[i].abs() < 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn rejects_non_positive_definite_matrix() {
+        let dense = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+        let a = CsMatrix::from(dense);
+        assert!(CsCholesky::new(&a).is_none());
+    }
+
+    #[test]
+    fn rejects_asymmetric_matrix() {
+        let dense = DMatrix::from_row_slice(2, 2, &[2.0, 1.0, 0.0, 2.0]);
+        let a = CsMatrix::from(dense);
+        assert!(CsCholesky::new(&a).is_none());
+    }
+}
@@ -0,0 +1,98 @@
+use alga::general::ClosedAdd;
+use num::Zero;
+
+use allocator::Allocator;
+use sparse::cs_matrix::{cumsum, CsMatrix};
+use {DefaultAllocator, Dim, Scalar, VectorN, U1};
+
+/// A matrix stored as a list of `(row, col, value)` triplets, in no particular order.
+///
+/// This is an assembly-friendly format: entries can be pushed one at a time (e.g. while
+/// assembling a FEM stiffness matrix), and repeated `(row, col)` triplets are summed together
+/// when converted to a `CsMatrix`.
+#[derive(Clone, Debug)]
+pub struct CooMatrix<N: Scalar, R: Dim, C: Dim> {
+    shape: (R, C),
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    vals: Vec<N>,
+}
+
+impl<N: Scalar, R: Dim, C: Dim> CooMatrix<N, R, C> {
+    /// Creates a new, empty `CooMatrix` with the given shape.
+    pub fn new_generic(nrows: R, ncols: C) -> Self {
+        CooMatrix {
+            shape: (nrows, ncols),
+            rows: Vec::new(),
+            cols: Vec::new(),
+            vals: Vec::new(),
+        }
+    }
+
+    /// The number of triplets pushed so far (this may count the same `(i, j)` several times).
+    pub fn len(&self) -> usize {
+        self.vals.len()
+    }
+
+    /// Returns `true` if no triplet has been pushed into this matrix yet.
+    pub fn is_empty(&self) -> bool {
+        self.vals.is_empty()
+    }
+
+    /// Reserves capacity for at least `additional` more triplets to be pushed into this matrix.
+    pub fn reserve(&mut self, additional: usize) {
+        self.rows.reserve(additional);
+        self.cols.reserve(additional);
+        self.vals.reserve(additional);
+    }
+
+    /// Appends the triplet `(i, j, v)` to this matrix.
+    ///
+    /// If an entry already exists at `(i, j)`, both values will be summed together once this
+    /// matrix is converted to a `CsMatrix`.
+    pub fn push(&mut self, i: usize, j: usize, v: N) {
+        assert!(i < self.shape.0.value(), "Row index out of bounds.");
+        assert!(j < self.shape.1.value(), "Column index out of bounds.");
+        self.rows.push(i);
+        self.cols.push(j);
+        self.vals.push(v);
+    }
+}
+
+impl<N: Scalar + Zero + ClosedAdd, R: Dim, C: Dim> From<CooMatrix<N, R, C>> for CsMatrix<N, R, C>
+where
+    DefaultAllocator: Allocator<usize, C>,
+{
+    fn from(coo: CooMatrix<N, R, C>) -> Self {
+        let (nrows, ncols) = coo.shape;
+        let nnz = coo.vals.len();
+
+        // Count the number of triplets per column, then turn that into column pointers.
+        let mut counts = VectorN::zeros_generic(ncols, U1);
+
+        for &j in &coo.cols {
+            counts[j] += 1;
+        }
+
+        let mut p = counts.clone();
+        let _ = cumsum(&mut counts, &mut p);
+
+        // Bucket every triplet into its column. `counts` is used as a per-column write cursor,
+        // starting at the same offsets as `p` (see `cumsum`).
+        let mut res = CsMatrix::new_uninitialized_generic(nrows, ncols, nnz);
+        res.data.p = p;
+
+        for k in 0..nnz {
+            let j = coo.cols[k];
+            let dest = counts[j];
+            res.data.i[dest] = coo.rows[k];
+            res.data.vals[dest] = coo.vals[k];
+            counts[j] += 1;
+        }
+
+        // Columns are not sorted and may contain duplicate row indices after bucketing;
+        // canonicalize them (this is also where duplicate `(i, j)` triplets get summed).
+        res.sort_and_dedup();
+        res
+    }
+}
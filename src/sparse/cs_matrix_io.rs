@@ -0,0 +1,139 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use num::Zero;
+
+use sparse::cs_matrix::CsStorage;
+use sparse::{CooMatrix, CsMatrix};
+use {Dim, Dynamic, Scalar};
+
+/// Parses a Matrix Market coordinate file into a sparse matrix.
+///
+/// Only the `real general` and `real symmetric` coordinate formats are supported. Returns
+/// `None` if the file cannot be parsed (missing banner, malformed header or entries).
+pub fn cs_matrix_from_matrix_market<N>(path: impl AsRef<Path>) -> Option<CsMatrix<N, Dynamic, Dynamic>>
+where
+    N: Scalar + Zero + FromStr,
+{
+    let content = fs::read_to_string(path).ok()?;
+    cs_matrix_from_matrix_market_str(&content)
+}
+
+/// Parses the content of a Matrix Market coordinate file into a sparse matrix.
+pub fn cs_matrix_from_matrix_market_str<N>(content: &str) -> Option<CsMatrix<N, Dynamic, Dynamic>>
+where
+    N: Scalar + Zero + FromStr,
+{
+    let mut lines = content.lines();
+
+    let banner = lines.next()?;
+    if !banner.starts_with("%%MatrixMarket") {
+        return None;
+    }
+
+    let banner_fields: Vec<_> = banner.split_whitespace().collect();
+    if banner_fields.get(1..3) != Some(&["matrix", "coordinate"]) {
+        return None;
+    }
+
+    let symmetric = match banner_fields.get(4) {
+        Some(&"general") | None => false,
+        Some(&"symmetric") => true,
+        _ => return None,
+    };
+
+    let mut header = None;
+
+    for line in &mut lines {
+        if line.starts_with('%') {
+            continue;
+        }
+
+        header = Some(line);
+        break;
+    }
+
+    let header: Vec<_> = header?.split_whitespace().collect();
+    if header.len() != 3 {
+        return None;
+    }
+
+    let nrows: usize = header[0].parse().ok()?;
+    let ncols: usize = header[1].parse().ok()?;
+    let nnz: usize = header[2].parse().ok()?;
+
+    let mut coo = CooMatrix::new_generic(Dynamic::new(nrows), Dynamic::new(ncols));
+    // Symmetric matrices push a mirrored entry for every off-diagonal triplet, so reserve twice
+    // as much room in that case to avoid reallocating while filling in the common case.
+    coo.reserve(if symmetric { nnz * 2 } else { nnz });
+
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fields: Vec<_> = line.split_whitespace().collect();
+        if fields.len() != 3 {
+            return None;
+        }
+
+        let i: usize = fields[0].parse().ok()?;
+        let j: usize = fields[1].parse().ok()?;
+        let v: N = fields[2].parse().ok()?;
+
+        // Matrix Market indices are 1-based.
+        let i = i.checked_sub(1)?;
+        let j = j.checked_sub(1)?;
+
+        if i >= nrows || j >= ncols || (symmetric && (j >= nrows || i >= ncols)) {
+            return None;
+        }
+
+        if symmetric && i != j {
+            coo.push(j, i, v);
+        }
+
+        coo.push(i, j, v);
+    }
+
+    Some(coo.into())
+}
+
+/// Writes `m` to `path` using the Matrix Market coordinate format.
+pub fn cs_matrix_to_matrix_market<N, R, C, S>(m: &CsMatrix<N, R, C, S>, path: impl AsRef<Path>) -> io::Result<()>
+where
+    N: Scalar + Display,
+    R: Dim,
+    C: Dim,
+    S: CsStorage<N, R, C>,
+{
+    let mut file = fs::File::create(path)?;
+    write_matrix_market(m, &mut file)
+}
+
+/// Writes `m` to `writer` using the Matrix Market coordinate format.
+pub fn write_matrix_market<N, R, C, S, W>(m: &CsMatrix<N, R, C, S>, writer: &mut W) -> io::Result<()>
+where
+    N: Scalar + Display,
+    R: Dim,
+    C: Dim,
+    S: CsStorage<N, R, C>,
+    W: Write,
+{
+    let (nrows, ncols) = m.data.shape();
+    writeln!(writer, "%%MatrixMarket matrix coordinate real general")?;
+    writeln!(writer, "{} {} {}", nrows.value(), ncols.value(), m.nvalues())?;
+
+    for j in 0..ncols.value() {
+        for vi in m.data.column_range(j) {
+            let i = m.data.row_index(vi);
+            let v = m.data.get_value(vi);
+            writeln!(writer, "{} {} {}", i + 1, j + 1, v)?;
+        }
+    }
+
+    Ok(())
+}
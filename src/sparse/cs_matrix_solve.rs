@@ -0,0 +1,110 @@
+use alga::general::{ClosedDiv, ClosedMul, ClosedSub};
+use num::Zero;
+
+use constraint::{DimEq, ShapeConstraint};
+use sparse::cs_matrix::{CsMatrix, CsStorage};
+use storage::StorageMut;
+use {Dim, Matrix, Scalar};
+
+impl<N: Scalar, R: Dim, C: Dim, S: CsStorage<N, R, C>> CsMatrix<N, R, C, S> {
+    /// Solve a lower-triangular system with a dense right-hand-side, in-place.
+    ///
+    /// This matrix is assumed to be lower-triangular, with its diagonal stored as the first
+    /// entry of each column (as produced e.g. by a Cholesky or LU factorization). The solution
+    /// overwrites `b`.
+    pub fn solve_lower_triangular_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<N, R2, C2, S2>,
+    ) -> bool
+    where
+        N: ClosedDiv + ClosedMul + ClosedSub + Zero,
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: DimEq<R, C> + DimEq<R2, R>,
+    {
+        let (nrows, ncols) = self.data.shape();
+        assert_eq!(
+            nrows.value(),
+            ncols.value(),
+            "The triangular matrix must be square."
+        );
+
+        for mut col in b.column_iter_mut() {
+            for j in 0..ncols.value() {
+                let mut column_range = self.data.column_range(j);
+                let diag_i = match column_range.next() {
+                    Some(diag_i) if unsafe { self.data.row_index_unchecked(diag_i) } == j => diag_i,
+                    _ => return false,
+                };
+                let diag = unsafe { *self.data.get_value_unchecked(diag_i) };
+
+                if diag.is_zero() {
+                    return false;
+                }
+
+                col[j] /= diag;
+                let xj = col[j];
+
+                for vi in column_range {
+                    unsafe {
+                        let i = self.data.row_index_unchecked(vi);
+                        col[i] -= *self.data.get_value_unchecked(vi) * xj;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Solve an upper-triangular system with a dense right-hand-side, in-place.
+    ///
+    /// This matrix is assumed to be upper-triangular, with its diagonal stored as the last
+    /// entry of each column (the transpose convention of `solve_lower_triangular_mut`). The
+    /// solution overwrites `b`.
+    pub fn solve_upper_triangular_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<N, R2, C2, S2>,
+    ) -> bool
+    where
+        N: ClosedDiv + ClosedMul + ClosedSub + Zero,
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: DimEq<R, C> + DimEq<R2, R>,
+    {
+        let (nrows, ncols) = self.data.shape();
+        assert_eq!(
+            nrows.value(),
+            ncols.value(),
+            "The triangular matrix must be square."
+        );
+
+        for mut col in b.column_iter_mut() {
+            for j in (0..ncols.value()).rev() {
+                let column_range = self.data.column_range(j);
+                let nvals = column_range.end - column_range.start;
+
+                if nvals == 0 || unsafe { self.data.row_index_unchecked(column_range.start + nvals - 1) } != j {
+                    return false;
+                }
+
+                let diag_i = column_range.start + nvals - 1;
+                let diag = unsafe { *self.data.get_value_unchecked(diag_i) };
+
+                if diag.is_zero() {
+                    return false;
+                }
+
+                col[j] /= diag;
+                let xj = col[j];
+
+                for vi in column_range.start..diag_i {
+                    unsafe {
+                        let i = self.data.row_index_unchecked(vi);
+                        col[i] -= *self.data.get_value_unchecked(vi) * xj;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
@@ -0,0 +1,16 @@
+//! Data structures for storing sparse matrices.
+
+pub use self::cs_matrix::{
+    CsMatrix, CsStorage, CsStorageMut, CsStructureError, CsVecStorage, CsVector,
+};
+pub use self::cs_matrix_cholesky::CsCholesky;
+pub use self::cs_matrix_coo::CooMatrix;
+#[cfg(feature = "io")]
+pub use self::cs_matrix_io::{cs_matrix_from_matrix_market, cs_matrix_to_matrix_market};
+
+mod cs_matrix;
+mod cs_matrix_cholesky;
+mod cs_matrix_coo;
+#[cfg(feature = "io")]
+mod cs_matrix_io;
+mod cs_matrix_solve;